@@ -0,0 +1,30 @@
+use iocraft::prelude::*;
+
+#[derive(Clone)]
+struct Theme {
+    accent_color: Color,
+}
+
+#[component]
+fn Greeting(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let theme = hooks.use_context::<Theme>();
+
+    element! {
+        Text(content: "Hello from a deeply nested component!", color: theme.get().accent_color)
+    }
+}
+
+#[component]
+fn App(_hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    element! {
+        ContextProvider(value: Theme { accent_color: Color::Magenta }) {
+            View(border_style: BorderStyle::Round, padding: 1) {
+                Greeting
+            }
+        }
+    }
+}
+
+fn main() {
+    smol::block_on(element!(App).render_loop()).unwrap();
+}
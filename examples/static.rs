@@ -1,35 +1,50 @@
 use iocraft::prelude::*;
 use std::time::Duration;
 
+#[derive(Clone, Default)]
+struct TestRunState {
+    completed_tests: Vec<String>,
+    test_count: usize,
+}
+
+enum TestRunAction {
+    TestPassed(String),
+}
+
+fn test_run_reducer(state: &mut TestRunState, action: TestRunAction) {
+    match action {
+        TestRunAction::TestPassed(message) => {
+            state.completed_tests.push(message);
+            state.test_count += 1;
+        }
+    }
+}
+
 #[component]
 fn TestRunner(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
-    let mut completed_tests = hooks.use_state(|| Vec::<String>::new());
-    let mut test_count = hooks.use_state(|| 0);
+    let (state, dispatch) = hooks.use_reducer(test_run_reducer, TestRunState::default);
 
     // Simulate adding completed tests over time
     hooks.use_future({
-        let mut completed_tests = completed_tests.clone();
-        let mut test_count = test_count.clone();
+        let dispatch = dispatch.clone();
         async move {
             for i in 1..=10 {
                 smol::Timer::after(Duration::from_millis(300)).await;
 
-                // Add completed test to the static list
-                completed_tests
-                    .write()
-                    .push(format!("✓ Test #{} passed", i));
-                test_count.set(i);
+                dispatch.dispatch(TestRunAction::TestPassed(format!("✓ Test #{} passed", i)));
             }
         }
     });
 
-    let status_text = if *test_count.read() == 10 {
+    let state = state.get();
+
+    let status_text = if state.test_count == 10 {
         "All tests completed! 🎉"
     } else {
         "Running tests..."
     };
 
-    let status_color = if *test_count.read() == 10 {
+    let status_color = if state.test_count == 10 {
         Color::Cyan
     } else {
         Color::White
@@ -38,11 +53,11 @@ fn TestRunner(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
     element! {
         View(flex_direction: FlexDirection::Column) {
             // Static section - shows completed tests that won't be re-rendered
-            Static(items: completed_tests.read().clone())
+            Static(items: state.completed_tests.clone())
 
             // Dynamic section - shows current progress
             View(margin_top: 1, padding: 1, border_style: BorderStyle::Round) {
-                Text(content: format!("{} ({}/10 completed)", status_text, *test_count.read()), color: status_color, weight: Weight::Bold)
+                Text(content: format!("{} ({}/10 completed)", status_text, state.test_count), color: status_color, weight: Weight::Bold)
             }
         }
     }
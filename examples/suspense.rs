@@ -0,0 +1,27 @@
+use iocraft::prelude::*;
+use std::time::Duration;
+
+#[component]
+fn Example(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let data = hooks.use_suspense(async {
+        smol::Timer::after(Duration::from_secs(3)).await;
+        "some data, loaded after a few seconds".to_string()
+    });
+
+    element! {
+        Text(content: data.unwrap_or_default())
+    }
+}
+
+#[component]
+fn App(_hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    element! {
+        Suspense(fallback: element!(Text(content: "Loading...")).into()) {
+            Example
+        }
+    }
+}
+
+fn main() {
+    smol::block_on(element!(App).render_loop()).unwrap();
+}
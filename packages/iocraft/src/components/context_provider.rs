@@ -0,0 +1,78 @@
+use crate::{AnyElement, Component, ComponentUpdater, Hooks, Props};
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// The props which can be passed to the [`ContextProvider`] component.
+#[non_exhaustive]
+#[derive(Props)]
+pub struct ContextProviderProps<'a, T: Clone + 'static> {
+    /// The value to publish to descendants.
+    pub value: Option<T>,
+
+    /// The children elements to render, with `value` available via `use_context`.
+    pub children: Vec<AnyElement<'a>>,
+}
+
+impl<T: Clone + 'static> Default for ContextProviderProps<'_, T> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            children: Vec::new(),
+        }
+    }
+}
+
+/// `ContextProvider` publishes a value of type `T` to all descendants, which may read it
+/// with `hooks.use_context::<T>()` without it being threaded through every layer of props
+/// in between.
+///
+/// This is well suited to cross-cutting concerns such as a shared theme, an app-wide
+/// output sink, or global configuration that many deeply nested components need access
+/// to.
+pub struct ContextProvider<T: Clone + 'static> {
+    value: Option<T>,
+}
+
+impl<T: Clone + 'static> Default for ContextProvider<T> {
+    fn default() -> Self {
+        Self { value: None }
+    }
+}
+
+impl<T: Clone + 'static> Component for ContextProvider<T> {
+    type Props<'a> = ContextProviderProps<'a, T>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self::default()
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: Hooks,
+        updater: &mut ComponentUpdater,
+    ) {
+        self.value = props.value.clone();
+        let children = &mut props.children;
+
+        // Published only for the duration of descending into `children`, so nested
+        // providers of the same type shadow us correctly and our value doesn't leak to
+        // siblings rendered afterward.
+        match &self.value {
+            Some(value) => {
+                crate::context::provide(value.clone(), || {
+                    updater.update_children(children.iter_mut(), None);
+                });
+            }
+            None => {
+                updater.update_children(children.iter_mut(), None);
+            }
+        }
+    }
+
+    fn poll_change(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        Poll::Pending
+    }
+}
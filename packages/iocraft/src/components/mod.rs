@@ -0,0 +1,7 @@
+mod context_provider;
+mod static_component;
+mod suspense;
+
+pub use context_provider::*;
+pub use static_component::*;
+pub use suspense::*;
@@ -0,0 +1,107 @@
+use crate::{AnyElement, Component, ComponentUpdater, Hooks, Props, State};
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// The props which can be passed to the [`Suspense`] component.
+#[non_exhaustive]
+#[derive(Props)]
+pub struct SuspenseProps<'a> {
+    /// The element to render while any descendant future registered via
+    /// `hooks.use_suspense` is still pending.
+    pub fallback: AnyElement<'a>,
+
+    /// The children elements to render once all descendant suspended futures have
+    /// resolved.
+    pub children: Vec<AnyElement<'a>>,
+}
+
+/// A handle shared with descendants via context, used by `hooks.use_suspense` to register
+/// and resolve pending futures against the nearest enclosing [`Suspense`] boundary.
+#[derive(Clone)]
+pub(crate) struct SuspenseBoundary {
+    pending: State<usize>,
+}
+
+impl SuspenseBoundary {
+    pub(crate) fn register(&self) -> SuspenseTask {
+        *self.pending.write() += 1;
+        SuspenseTask {
+            pending: self.pending.clone(),
+        }
+    }
+}
+
+/// Returned by [`SuspenseBoundary::register`]; resolves the registration once the
+/// corresponding future completes.
+pub(crate) struct SuspenseTask {
+    pending: State<usize>,
+}
+
+impl SuspenseTask {
+    pub(crate) fn resolve(self) {
+        *self.pending.write() -= 1;
+    }
+}
+
+/// `Suspense` renders its `fallback` prop until every future registered beneath it via
+/// `hooks.use_suspense` has resolved, then swaps in its real `children`.
+///
+/// This is well suited to components that load data asynchronously and have nothing
+/// meaningful to render until that data arrives. If new futures are suspended after the
+/// boundary has already shown its children (e.g. a descendant remounts with fresh async
+/// work), `Suspense` re-enters the fallback state until they resolve too.
+pub struct Suspense {
+    boundary: Option<SuspenseBoundary>,
+}
+
+impl Default for Suspense {
+    fn default() -> Self {
+        Self { boundary: None }
+    }
+}
+
+impl Component for Suspense {
+    type Props<'a> = SuspenseProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self::default()
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater,
+    ) {
+        let pending = hooks.use_state(|| 0usize);
+        let boundary = self
+            .boundary
+            .get_or_insert_with(|| SuspenseBoundary { pending });
+
+        // Published only for the duration of descending into our children/fallback, the
+        // same way `ContextProvider` publishes its value, so every `use_suspense` call
+        // beneath us can register against this boundary's pending counter.
+        crate::context::provide(boundary.clone(), || {
+            // A child that suspends for the first time registers against the boundary
+            // (bumping `pending`) synchronously inside this call, so a count read before
+            // descending can't see it - only descend into real children while nothing was
+            // already known to be pending, then re-check afterward before deciding what
+            // this frame actually shows.
+            if *boundary.pending.read() == 0 {
+                updater.update_children(props.children.iter_mut(), None);
+            }
+
+            if *boundary.pending.read() > 0 {
+                updater.update_children(std::iter::once(&mut props.fallback), None);
+            }
+        });
+    }
+
+    fn poll_change(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        // Re-rendering happens as a side effect of the shared pending `State` changing,
+        // which already schedules an update; the boundary itself has no changes to poll.
+        Poll::Pending
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+};
+
+thread_local! {
+    static STACK: RefCell<Vec<HashMap<TypeId, Box<dyn Any>>>> = RefCell::new(Vec::new());
+}
+
+/// Publishes `value` for the duration of `f`, making it visible to any [`get`] call made
+/// while `f` runs - in particular, by descendants whose `update` is invoked from within
+/// `f` (e.g. via `updater.update_children`).
+///
+/// Used by [`ContextProvider`](crate::components::ContextProvider) and
+/// [`Suspense`](crate::components::Suspense) to publish a value to everything beneath
+/// them without threading it through every layer of props in between.
+pub(crate) fn provide<T: Clone + 'static, R>(value: T, f: impl FnOnce() -> R) -> R {
+    let _frame = ProvidedFrame::push(value);
+    f()
+}
+
+/// Pushes a stack frame on construction and pops it on drop, so the frame is removed even
+/// if `f` unwinds - otherwise a panicking descendant would leave the thread-local stack
+/// permanently corrupted for every later render on that thread.
+struct ProvidedFrame;
+
+impl ProvidedFrame {
+    fn push<T: Clone + 'static>(value: T) -> Self {
+        STACK.with(|stack| {
+            let mut frame = HashMap::new();
+            frame.insert(TypeId::of::<T>(), Box::new(value) as Box<dyn Any>);
+            stack.borrow_mut().push(frame);
+        });
+        Self
+    }
+}
+
+impl Drop for ProvidedFrame {
+    fn drop(&mut self) {
+        STACK.with(|stack| {
+            stack.borrow_mut().pop();
+        });
+    }
+}
+
+/// Returns the nearest value of type `T` published by an enclosing [`provide`] call,
+/// searching from the innermost frame outward.
+pub(crate) fn get<T: Clone + 'static>() -> Option<T> {
+    STACK.with(|stack| {
+        stack
+            .borrow()
+            .iter()
+            .rev()
+            .find_map(|frame| frame.get(&TypeId::of::<T>()))
+            .and_then(|value| value.downcast_ref::<T>())
+            .cloned()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_outside_any_provide_is_none() {
+        assert_eq!(get::<i32>(), None);
+    }
+
+    #[test]
+    fn test_get_sees_the_innermost_provided_value() {
+        provide(1i32, || {
+            assert_eq!(get::<i32>(), Some(1));
+            provide(2i32, || {
+                assert_eq!(get::<i32>(), Some(2));
+            });
+            assert_eq!(get::<i32>(), Some(1));
+        });
+        assert_eq!(get::<i32>(), None);
+    }
+
+    #[test]
+    fn test_get_distinguishes_by_type() {
+        provide(1i32, || {
+            provide("a".to_string(), || {
+                assert_eq!(get::<i32>(), Some(1));
+                assert_eq!(get::<String>(), Some("a".to_string()));
+            });
+        });
+    }
+
+    #[test]
+    fn test_provide_pops_its_frame_even_if_f_panics() {
+        let result = std::panic::catch_unwind(|| {
+            provide(1i32, || {
+                panic!("descendant update panicked");
+            });
+        });
+        assert!(result.is_err());
+        assert_eq!(get::<i32>(), None);
+    }
+}
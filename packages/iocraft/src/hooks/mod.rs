@@ -0,0 +1,7 @@
+mod use_context;
+mod use_reducer;
+mod use_suspense;
+
+pub use use_context::*;
+pub use use_reducer::*;
+pub use use_suspense::*;
@@ -0,0 +1,45 @@
+use crate::hooks::Hooks;
+
+/// A handle to a value published by the nearest enclosing [`ContextProvider`](crate::components::ContextProvider).
+pub struct ContextHandle<T> {
+    value: T,
+}
+
+impl<T: Clone> ContextHandle<T> {
+    /// Returns a clone of the currently provided value.
+    pub fn get(&self) -> T {
+        self.value.clone()
+    }
+}
+
+/// Extends [`Hooks`] with [`use_context`](UseContext::use_context).
+pub trait UseContext {
+    /// Locates the nearest value of type `T` published by an enclosing
+    /// [`ContextProvider`](crate::components::ContextProvider).
+    ///
+    /// Because a component's `update` re-reads context on every render (the same way it
+    /// re-reads any other hook), this naturally picks up whatever the provider most
+    /// recently published - no separate subscription bookkeeping is needed.
+    ///
+    /// Panics if no enclosing `ContextProvider<T>` exists.
+    fn use_context<T: Clone + 'static>(&mut self) -> ContextHandle<T>;
+
+    /// Like [`use_context`](Self::use_context), but returns `None` instead of panicking
+    /// when no enclosing provider exists.
+    fn try_use_context<T: Clone + 'static>(&mut self) -> Option<ContextHandle<T>>;
+}
+
+impl UseContext for Hooks<'_, '_> {
+    fn use_context<T: Clone + 'static>(&mut self) -> ContextHandle<T> {
+        self.try_use_context().unwrap_or_else(|| {
+            panic!(
+                "no `ContextProvider<{}>` found among ancestors of this component",
+                std::any::type_name::<T>()
+            )
+        })
+    }
+
+    fn try_use_context<T: Clone + 'static>(&mut self) -> Option<ContextHandle<T>> {
+        crate::context::get::<T>().map(|value| ContextHandle { value })
+    }
+}
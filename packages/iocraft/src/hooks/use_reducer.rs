@@ -0,0 +1,230 @@
+use crate::hooks::Hooks;
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+/// The state, action queue, and reducer backing a [`UseReducer::use_reducer`] hook.
+///
+/// Kept free of any dependency on the hook-storage machinery so its fold-and-wake
+/// behavior - in particular, that a `dispatch` from another thread actually wakes a
+/// pending `poll_change` - can be unit tested directly.
+struct ReducerCore<State, Action> {
+    state: Mutex<State>,
+    queue: Mutex<VecDeque<Action>>,
+    waker: Mutex<Option<Waker>>,
+    reducer: Box<dyn Fn(&mut State, Action) + Send + Sync>,
+}
+
+impl<State, Action> ReducerCore<State, Action> {
+    fn new(state: State, reducer: impl Fn(&mut State, Action) + Send + Sync + 'static) -> Self {
+        Self {
+            state: Mutex::new(state),
+            queue: Mutex::new(VecDeque::new()),
+            waker: Mutex::new(None),
+            reducer: Box::new(reducer),
+        }
+    }
+
+    fn dispatch(&self, action: Action) {
+        self.queue.lock().unwrap().push_back(action);
+        // `dispatch` is routinely called from a `use_future` task running on another
+        // task/thread; without waking the waker stored below, a queued action would sit
+        // until something unrelated happened to cause a repoll.
+        if let Some(waker) = self.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+
+    /// Drains any queued actions into `state`, folding them through the reducer in
+    /// dispatch order. Returns `Poll::Ready` if at least one action was applied, in which
+    /// case the component is marked dirty; otherwise stores `cx`'s waker so a later
+    /// `dispatch` wakes this poll.
+    fn poll_change(&self, cx: &mut Context<'_>) -> Poll<()> {
+        let mut queue = self.queue.lock().unwrap();
+        if queue.is_empty() {
+            *self.waker.lock().unwrap() = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        let mut state = self.state.lock().unwrap();
+        for action in queue.drain(..) {
+            (self.reducer)(&mut state, action);
+        }
+        Poll::Ready(())
+    }
+}
+
+/// A read-only view of the state managed by a [`UseReducer::use_reducer`] hook.
+///
+/// Cloning a `ReducerState` is cheap; all clones observe the same underlying state, which
+/// is only ever mutated by folding dispatched actions through the reducer.
+pub struct ReducerState<State, Action> {
+    core: Arc<ReducerCore<State, Action>>,
+}
+
+impl<State, Action> Clone for ReducerState<State, Action> {
+    fn clone(&self) -> Self {
+        Self {
+            core: self.core.clone(),
+        }
+    }
+}
+
+impl<State: Clone, Action> ReducerState<State, Action> {
+    /// Returns a clone of the current state.
+    pub fn get(&self) -> State {
+        self.core.state.lock().unwrap().clone()
+    }
+}
+
+/// Queues actions to be applied by the reducer registered via [`UseReducer::use_reducer`].
+///
+/// A `Dispatcher` may be cloned into `use_future` closures so background tasks can report
+/// state transitions without holding a mutable borrow of the component.
+pub struct Dispatcher<State, Action> {
+    core: Arc<ReducerCore<State, Action>>,
+}
+
+impl<State, Action> Clone for Dispatcher<State, Action> {
+    fn clone(&self) -> Self {
+        Self {
+            core: self.core.clone(),
+        }
+    }
+}
+
+impl<State, Action> Dispatcher<State, Action> {
+    /// Queues an action for the reducer to apply before the next render.
+    pub fn dispatch(&self, action: Action) {
+        self.core.dispatch(action);
+    }
+}
+
+struct UseReducerImpl<State, Action> {
+    core: Arc<ReducerCore<State, Action>>,
+}
+
+impl<State: Unpin, Action: Unpin> crate::hooks::Hook for UseReducerImpl<State, Action> {
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        self.core.poll_change(cx)
+    }
+}
+
+/// Extends [`Hooks`] with [`use_reducer`](UseReducer::use_reducer).
+pub trait UseReducer {
+    /// Models a component's state transitions as a single reducer, returning the current
+    /// state plus a [`Dispatcher`] used to queue actions.
+    ///
+    /// `reducer` is applied once per dispatched action, in dispatch order, at the start of
+    /// the component's next update; the component is marked dirty whenever the action
+    /// queue was non-empty. This lets components that currently juggle several coupled
+    /// `use_state` cells model their transitions as one state machine instead.
+    fn use_reducer<State, Action>(
+        &mut self,
+        reducer: impl Fn(&mut State, Action) + Send + Sync + 'static,
+        initial: impl FnOnce() -> State,
+    ) -> (ReducerState<State, Action>, Dispatcher<State, Action>)
+    where
+        State: Unpin + 'static,
+        Action: Unpin + 'static;
+}
+
+impl UseReducer for Hooks<'_, '_> {
+    fn use_reducer<State, Action>(
+        &mut self,
+        reducer: impl Fn(&mut State, Action) + Send + Sync + 'static,
+        initial: impl FnOnce() -> State,
+    ) -> (ReducerState<State, Action>, Dispatcher<State, Action>)
+    where
+        State: Unpin + 'static,
+        Action: Unpin + 'static,
+    {
+        let hook = self.use_hook(move || UseReducerImpl {
+            core: Arc::new(ReducerCore::new(initial(), reducer)),
+        });
+        (
+            ReducerState {
+                core: hook.core.clone(),
+            },
+            Dispatcher {
+                core: hook.core.clone(),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::atomic::{AtomicBool, Ordering},
+        task::Wake,
+    };
+
+    struct FlagWaker(AtomicBool);
+
+    impl Wake for FlagWaker {
+        fn wake(self: Arc<Self>) {
+            self.wake_by_ref();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn test_waker() -> (Arc<FlagWaker>, Waker) {
+        let flag = Arc::new(FlagWaker(AtomicBool::new(false)));
+        let waker = Waker::from(flag.clone());
+        (flag, waker)
+    }
+
+    #[derive(Clone, Default, PartialEq, Debug)]
+    struct Counter(i32);
+
+    enum Op {
+        Add(i32),
+    }
+
+    fn reduce(state: &mut Counter, action: Op) {
+        match action {
+            Op::Add(n) => state.0 += n,
+        }
+    }
+
+    #[test]
+    fn test_poll_change_is_pending_on_an_empty_queue() {
+        let core = ReducerCore::new(Counter::default(), reduce);
+        let (_flag, waker) = test_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert_eq!(core.poll_change(&mut cx), Poll::Pending);
+    }
+
+    #[test]
+    fn test_poll_change_folds_queued_actions_in_dispatch_order() {
+        let core = ReducerCore::new(Counter::default(), reduce);
+        core.dispatch(Op::Add(1));
+        core.dispatch(Op::Add(2));
+
+        let (_flag, waker) = test_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert_eq!(core.poll_change(&mut cx), Poll::Ready(()));
+        assert_eq!(*core.state.lock().unwrap(), Counter(3));
+    }
+
+    #[test]
+    fn test_dispatch_wakes_a_previously_registered_waker() {
+        let core = ReducerCore::new(Counter::default(), reduce);
+        let (flag, waker) = test_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        // The queue starts empty, so this registers `waker` for later.
+        assert_eq!(core.poll_change(&mut cx), Poll::Pending);
+        assert!(!flag.0.load(Ordering::SeqCst));
+
+        core.dispatch(Op::Add(1));
+        assert!(flag.0.load(Ordering::SeqCst));
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{components::SuspenseBoundary, hooks::Hooks};
+use std::future::Future;
+
+/// Extends [`Hooks`] with [`use_suspense`](UseSuspense::use_suspense).
+pub trait UseSuspense {
+    /// Registers `future` with the nearest enclosing [`Suspense`](crate::components::Suspense)
+    /// boundary, returning its output once the future resolves (and `None` on every render
+    /// until then).
+    ///
+    /// While `future`, or any other future suspended under the same boundary, is still
+    /// pending, `Suspense` renders its `fallback` instead of real children. This is
+    /// intended for components with no meaningful content to show until async work such
+    /// as a data fetch completes.
+    ///
+    /// Panics if there is no enclosing `Suspense` boundary.
+    fn use_suspense<Fut, Out>(&mut self, future: Fut) -> Option<Out>
+    where
+        Fut: Future<Output = Out> + 'static,
+        Out: Clone + Unpin + 'static;
+}
+
+impl UseSuspense for Hooks<'_, '_> {
+    fn use_suspense<Fut, Out>(&mut self, future: Fut) -> Option<Out>
+    where
+        Fut: Future<Output = Out> + 'static,
+        Out: Clone + Unpin + 'static,
+    {
+        let mut result = self.use_state(|| None::<Out>);
+        let boundary = self.use_context::<SuspenseBoundary>().get();
+
+        if result.read().is_none() {
+            let task = boundary.register();
+            self.use_future({
+                let mut result = result.clone();
+                async move {
+                    let output = future.await;
+                    result.set(Some(output));
+                    task.resolve();
+                }
+            });
+        }
+
+        result.read().clone()
+    }
+}
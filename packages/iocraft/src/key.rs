@@ -0,0 +1,190 @@
+use std::collections::{HashMap, HashSet};
+
+/// Identifies an element within a keyed child list, as compared by [`diff_keyed_children`].
+///
+/// [`ComponentUpdater::update_children`](crate::ComponentUpdater::update_children) does not
+/// use this type or call [`diff_keyed_children`]; applying a [`KeyedDiff`] to component
+/// instances is left to the caller.
+pub type ElementKey = String;
+
+/// The outcome of diffing an old keyed child list against a new one.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct KeyedDiff {
+    /// For each position in the new list, the index it came from in the old list, or
+    /// `None` if the element is newly mounted.
+    pub sources: Vec<Option<usize>>,
+
+    /// Indices into the new list whose component can stay anchored in place, because
+    /// they're already in increasing order of their old index. Every other matched
+    /// element must be moved to its new position.
+    pub anchored: Vec<usize>,
+
+    /// Indices into the old list with no matching key in the new list, which must be
+    /// unmounted.
+    pub unmounted: Vec<usize>,
+}
+
+/// Diffs `old_keys` against `new_keys`, returning the minimal set of moves needed to
+/// reconcile them.
+///
+/// Builds a map from key to its index in `old_keys`, projects `new_keys` onto those old
+/// indices, and computes the longest increasing subsequence (LIS) of the projection: the
+/// elements within the LIS are already in relative order and need no move, so only the
+/// remaining matched elements - plus any newly mounted ones - are relocated.
+///
+/// # Panics
+///
+/// Panics if `old_keys` or `new_keys` contains a duplicate key; duplicate keys must be
+/// rejected or deterministically disambiguated before calling this function.
+pub fn diff_keyed_children(old_keys: &[ElementKey], new_keys: &[ElementKey]) -> KeyedDiff {
+    let mut old_index_by_key = HashMap::with_capacity(old_keys.len());
+    for (index, key) in old_keys.iter().enumerate() {
+        if old_index_by_key.insert(key.clone(), index).is_some() {
+            panic!("duplicate element key: {key:?}");
+        }
+    }
+
+    let mut seen_new_keys = HashSet::with_capacity(new_keys.len());
+    for key in new_keys {
+        if !seen_new_keys.insert(key) {
+            panic!("duplicate element key: {key:?}");
+        }
+    }
+
+    let sources: Vec<Option<usize>> = new_keys
+        .iter()
+        .map(|key| old_index_by_key.get(key).copied())
+        .collect();
+
+    let matched_old_indices: Vec<usize> = sources.iter().filter_map(|source| *source).collect();
+    let lis = longest_increasing_subsequence(&matched_old_indices);
+    let lis: HashSet<usize> = lis.into_iter().collect();
+
+    let mut anchored = Vec::with_capacity(lis.len());
+    let mut matched_position = 0;
+    for (new_index, source) in sources.iter().enumerate() {
+        if source.is_some() {
+            if lis.contains(&matched_position) {
+                anchored.push(new_index);
+            }
+            matched_position += 1;
+        }
+    }
+
+    let new_key_set: HashSet<&ElementKey> = new_keys.iter().collect();
+    let unmounted = old_keys
+        .iter()
+        .enumerate()
+        .filter(|(_, key)| !new_key_set.contains(key))
+        .map(|(index, _)| index)
+        .collect();
+
+    KeyedDiff {
+        sources,
+        anchored,
+        unmounted,
+    }
+}
+
+/// Returns the indices (into `values`) forming a longest strictly increasing subsequence,
+/// in ascending order.
+///
+/// Uses patience sorting: an O(n log n) `tails` array tracks, for each subsequence length
+/// found so far, the index of its smallest-valued ending element, and a `predecessors`
+/// array reconstructs the chosen indices once the scan completes.
+fn longest_increasing_subsequence(values: &[usize]) -> Vec<usize> {
+    let mut tails: Vec<usize> = Vec::new();
+    let mut predecessors: Vec<Option<usize>> = vec![None; values.len()];
+
+    for (index, &value) in values.iter().enumerate() {
+        let insertion_point = tails.partition_point(|&tail_index| values[tail_index] < value);
+
+        if insertion_point > 0 {
+            predecessors[index] = Some(tails[insertion_point - 1]);
+        }
+
+        if insertion_point == tails.len() {
+            tails.push(index);
+        } else {
+            tails[insertion_point] = index;
+        }
+    }
+
+    let mut sequence = Vec::with_capacity(tails.len());
+    let mut current = tails.last().copied();
+    while let Some(index) = current {
+        sequence.push(index);
+        current = predecessors[index];
+    }
+    sequence.reverse();
+    sequence
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lis_already_increasing() {
+        assert_eq!(
+            longest_increasing_subsequence(&[0, 1, 2, 3]),
+            vec![0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn test_lis_classic_example() {
+        let values = [2, 6, 3, 4, 1, 2, 9, 5, 8];
+        let lis = longest_increasing_subsequence(&values);
+        assert_eq!(lis.len(), 5);
+        for window in lis.windows(2) {
+            assert!(window[0] < window[1]);
+            assert!(values[window[0]] < values[window[1]]);
+        }
+    }
+
+    #[test]
+    fn test_diff_no_change_anchors_everything() {
+        let old = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let new = old.clone();
+        let diff = diff_keyed_children(&old, &new);
+        assert_eq!(diff.sources, vec![Some(0), Some(1), Some(2)]);
+        assert_eq!(diff.anchored, vec![0, 1, 2]);
+        assert!(diff.unmounted.is_empty());
+    }
+
+    #[test]
+    fn test_diff_reorder_minimizes_moves() {
+        // Moving "a" to the end should only require moving "a"; "b" and "c" stay anchored.
+        let old = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let new = vec!["b".to_string(), "c".to_string(), "a".to_string()];
+        let diff = diff_keyed_children(&old, &new);
+        assert_eq!(diff.sources, vec![Some(1), Some(2), Some(0)]);
+        assert_eq!(diff.anchored, vec![0, 1]);
+        assert!(diff.unmounted.is_empty());
+    }
+
+    #[test]
+    fn test_diff_mount_and_unmount() {
+        let old = vec!["a".to_string(), "b".to_string()];
+        let new = vec!["b".to_string(), "c".to_string()];
+        let diff = diff_keyed_children(&old, &new);
+        assert_eq!(diff.sources, vec![Some(1), None]);
+        assert_eq!(diff.unmounted, vec![0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate element key")]
+    fn test_diff_rejects_duplicate_old_keys() {
+        let old = vec!["a".to_string(), "a".to_string()];
+        diff_keyed_children(&old, &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate element key")]
+    fn test_diff_rejects_duplicate_new_keys() {
+        let old = vec!["a".to_string()];
+        let new = vec!["a".to_string(), "a".to_string()];
+        diff_keyed_children(&old, &new);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod components;
+mod context;
+pub mod hooks;
+pub mod key;
+pub mod render_sink;
+pub mod testing;
@@ -0,0 +1,143 @@
+use crate::{Color, Weight};
+
+/// The visual style accompanying a [`RenderMutation::DrawText`] instruction.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CellStyle {
+    /// The foreground color, or `None` to use the terminal's default.
+    pub color: Option<Color>,
+    /// The font weight.
+    pub weight: Weight,
+}
+
+/// A single drawing instruction for one frame.
+///
+/// A [`RenderSink`] consumes a stream of these instead of raw ANSI bytes, so a thin
+/// client (e.g. connected over a socket) can replay the same frame a local terminal would
+/// have shown, at a fraction of the bandwidth. The crate's render loop does not produce
+/// these mutations; they are built by whatever code drives a `RenderSink`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RenderMutation {
+    /// Clears a rectangular region of the dynamic viewport before it is redrawn.
+    ClearRegion {
+        row: u16,
+        col: u16,
+        width: u16,
+        height: u16,
+    },
+
+    /// Draws `content` starting at `(row, col)` with the given style.
+    DrawText {
+        row: u16,
+        col: u16,
+        content: String,
+        style: CellStyle,
+    },
+
+    /// Permanently commits `lines` above the dynamic viewport; never replayed again.
+    ///
+    /// This suits append-only content like the items of the
+    /// [`Static`](crate::components::Static) component: a client can commit these lines once
+    /// and forget them rather than redrawing them on every frame. `Static` itself does not
+    /// emit this mutation.
+    AppendStatic { lines: Vec<String> },
+}
+
+/// Consumes the mutations for a single frame.
+///
+/// [`TerminalRenderSink`] writes them as ANSI escape codes to a writer, and
+/// `ChannelRenderSink` (with the `serde` feature) forwards them to a channel. The crate's
+/// render loop does not use this trait and produces no mutations; a sink receives only
+/// the mutations its caller passes to [`apply`](Self::apply).
+pub trait RenderSink {
+    /// Applies one frame's worth of mutations, in order.
+    fn apply(&mut self, mutations: &[RenderMutation]);
+}
+
+/// A [`RenderSink`] that writes ANSI escape codes to a writer such as a local terminal.
+pub struct TerminalRenderSink<W> {
+    writer: W,
+}
+
+impl<W> TerminalRenderSink<W> {
+    /// Creates a sink that writes to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<W: std::io::Write> RenderSink for TerminalRenderSink<W> {
+    fn apply(&mut self, mutations: &[RenderMutation]) {
+        use crossterm::{
+            cursor::MoveTo,
+            style::{Attribute, Print, SetAttribute, SetForegroundColor},
+            QueueableCommand,
+        };
+
+        for mutation in mutations {
+            match mutation {
+                RenderMutation::ClearRegion {
+                    row,
+                    col,
+                    width,
+                    height,
+                } => {
+                    let blank = " ".repeat(*width as usize);
+                    for r in *row..*row + *height {
+                        let _ = self.writer.queue(MoveTo(*col, r));
+                        let _ = self.writer.queue(Print(&blank));
+                    }
+                }
+                RenderMutation::DrawText {
+                    row,
+                    col,
+                    content,
+                    style,
+                } => {
+                    let _ = self.writer.queue(MoveTo(*col, *row));
+                    if let Some(color) = style.color {
+                        let _ = self.writer.queue(SetForegroundColor(color.into()));
+                    }
+                    if style.weight == Weight::Bold {
+                        let _ = self.writer.queue(SetAttribute(Attribute::Bold));
+                    }
+                    let _ = self.writer.queue(Print(content));
+                    let _ = self.writer.queue(SetAttribute(Attribute::Reset));
+                }
+                RenderMutation::AppendStatic { lines } => {
+                    for line in lines {
+                        let _ = self.writer.queue(Print(line));
+                        let _ = self.writer.queue(Print("\r\n"));
+                    }
+                }
+            }
+        }
+        let _ = self.writer.flush();
+    }
+}
+
+/// A [`RenderSink`] that forwards each frame's mutations over a channel for a thin remote
+/// client to replay (LiveView-style), instead of writing ANSI to the local terminal.
+///
+/// Requires the `serde` feature, since [`RenderMutation`] must be serializable in order to
+/// cross a socket.
+#[cfg(feature = "serde")]
+pub struct ChannelRenderSink {
+    sender: std::sync::mpsc::Sender<Vec<RenderMutation>>,
+}
+
+#[cfg(feature = "serde")]
+impl ChannelRenderSink {
+    /// Creates a sink that sends each frame's mutations to `sender`.
+    pub fn new(sender: std::sync::mpsc::Sender<Vec<RenderMutation>>) -> Self {
+        Self { sender }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl RenderSink for ChannelRenderSink {
+    fn apply(&mut self, mutations: &[RenderMutation]) {
+        let _ = self.sender.send(mutations.to_vec());
+    }
+}
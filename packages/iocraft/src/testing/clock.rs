@@ -0,0 +1,79 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// A time source that reports how much time has elapsed.
+///
+/// [`RealClock`] is backed by the real wall clock and [`VirtualClock`] advances only when
+/// told to. The render loop does not read a `Clock`: deadlines such as
+/// `smol::Timer::after` are always measured against real time.
+pub trait Clock: Send + Sync {
+    /// Returns how much time has elapsed since the clock was created.
+    fn elapsed(&self) -> Duration;
+}
+
+/// A [`Clock`] backed by the real wall clock, measured from its creation.
+pub struct RealClock {
+    start: Instant,
+}
+
+impl Default for RealClock {
+    fn default() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Clock for RealClock {
+    fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+/// A [`Clock`] whose elapsed time only advances when [`VirtualClock::advance`] is called,
+/// so a test controls exactly how much time it reports.
+#[derive(Clone, Default)]
+pub struct VirtualClock {
+    elapsed: Arc<Mutex<Duration>>,
+}
+
+impl VirtualClock {
+    /// Creates a virtual clock starting at zero elapsed time.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advances the clock by `duration`; [`elapsed`](Clock::elapsed) reflects the change
+    /// immediately.
+    pub fn advance(&self, duration: Duration) {
+        *self.elapsed.lock().unwrap() += duration;
+    }
+}
+
+impl Clock for VirtualClock {
+    fn elapsed(&self) -> Duration {
+        *self.elapsed.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_virtual_clock_starts_at_zero() {
+        let clock = VirtualClock::new();
+        assert_eq!(clock.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_virtual_clock_advances_only_when_told() {
+        let clock = VirtualClock::new();
+        clock.advance(Duration::from_millis(300));
+        assert_eq!(clock.elapsed(), Duration::from_millis(300));
+        clock.advance(Duration::from_millis(300));
+        assert_eq!(clock.elapsed(), Duration::from_millis(600));
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{testing::clock::VirtualClock, AnyElement};
+use std::time::Duration;
+
+/// **Status: WIP.** This does not yet deliver a working `render_step` - see the panic
+/// message on that method for why - and should not be treated as a finished feature.
+///
+/// A deterministic time source for driving incremental, time-based components in tests.
+///
+/// `.to_string()` only ever drives one synchronous render from a fresh component tree,
+/// which can't exercise time-driven behavior like `TestRunner`'s periodic test completions
+/// or `Static`'s "only render new items" contract without real wall-clock sleeps.
+/// `MockTerminal` is meant to own a [`VirtualClock`] that a render loop polls deadlines
+/// (e.g. `smol::Timer::after`) against instead of the real wall clock, so a test can
+/// advance time deterministically rather than sleeping, and a `render_step` that drives one
+/// `update`/`poll_change` tick and returns the resulting frame.
+///
+/// Only the clock half is implemented. There is no render-loop entry point that both
+/// accepts a pluggable clock and persists component/hook state across steps the way
+/// `Element::render_loop` persists it across frames, and no way to construct the
+/// `ComponentUpdater`/`Hooks` a step would need to drive a `Component` directly from outside
+/// the crate's render loop. `render_step` is left unimplemented rather than faked until one
+/// of those exists.
+pub struct MockTerminal {
+    clock: VirtualClock,
+}
+
+impl Default for MockTerminal {
+    fn default() -> Self {
+        Self {
+            clock: VirtualClock::new(),
+        }
+    }
+}
+
+impl MockTerminal {
+    /// Creates a harness whose virtual clock starts at zero elapsed time.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The clock driving this harness.
+    pub fn clock(&self) -> &VirtualClock {
+        &self.clock
+    }
+
+    /// Advances the virtual clock by `duration`, allowing any deadlines within it to become
+    /// due the next time something polling against [`clock`](Self::clock) checks.
+    pub fn advance_time(&self, duration: Duration) {
+        self.clock.advance(duration);
+    }
+
+    /// **Not yet implemented.** This is meant to poll every future registered under
+    /// `element` once against [`clock`](Self::clock), fold in any resulting state writes,
+    /// re-render, and return the resulting frame, reusing the same component/hook state
+    /// across calls the way a real render loop would.
+    ///
+    /// Left explicitly unimplemented: doing this for real needs a render-loop entry point
+    /// that both accepts [`clock`](Self::clock) in place of the real wall clock and exposes
+    /// a way to construct the `ComponentUpdater`/`Hooks` a single step would drive, and
+    /// neither exists in this crate yet.
+    pub fn render_step(&mut self, _element: impl Into<AnyElement<'static>>) -> String {
+        unimplemented!(
+            "MockTerminal::render_step needs a render-loop entry point that accepts a \
+             pluggable clock and persists component/hook state across steps; see the struct \
+             docs"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance_time_advances_the_underlying_clock() {
+        let terminal = MockTerminal::new();
+        assert_eq!(terminal.clock().elapsed(), Duration::ZERO);
+
+        terminal.advance_time(Duration::from_millis(300));
+        assert_eq!(terminal.clock().elapsed(), Duration::from_millis(300));
+
+        terminal.advance_time(Duration::from_millis(300));
+        assert_eq!(terminal.clock().elapsed(), Duration::from_millis(600));
+    }
+
+    #[test]
+    #[should_panic(expected = "render-loop entry point")]
+    fn test_render_step_is_not_yet_implemented() {
+        use crate::prelude::*;
+
+        let mut terminal = MockTerminal::new();
+        terminal.render_step(element!(Text(content: "x")));
+    }
+}
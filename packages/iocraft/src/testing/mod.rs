@@ -0,0 +1,5 @@
+mod clock;
+mod mock_terminal;
+
+pub use clock::*;
+pub use mock_terminal::*;